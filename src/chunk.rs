@@ -0,0 +1,241 @@
+//! On-disk chunk container format.
+//!
+//! Every chunk [`World`](crate::World) persists is wrapped in a small,
+//! self-describing [`ChunkHeader`] so stored worlds are portable and
+//! corruption (truncation, a mismatched format, a bit-rotted disk) is
+//! caught immediately instead of surfacing later as a garbled decode.
+
+use bytes::{Buf, BufMut};
+
+/// Magic signature written at the start of every chunk container.
+///
+/// The first byte is deliberately non-ASCII and the tail reproduces the
+/// `CR LF 1A LF` trick used by formats like PNG, so a file mangled by a
+/// text-mode transfer (CRLF translation, truncation at a stray `0x1A`)
+/// is caught on the very first read instead of decoding into garbage.
+pub const MAGIC: [u8; 8] = [0xEE, b'S', b'D', b'B', b'\r', b'\n', 0x1A, b'\n'];
+
+/// Size in bytes of an encoded [`ChunkHeader`] for `DIMS` dimensions.
+///
+/// `encrypted` must match whether the header carries a
+/// [`ChunkEncryption`], since that makes the encoded size variable;
+/// readers learn this from the header's presence byte before computing
+/// where the header ends.
+pub const fn encoded_len(dims: usize, encrypted: bool) -> usize {
+    MAGIC.len() + 1 + 4 + 4 + dims * 8 + 4 + 4 + 1 + if encrypted { 4 + 12 } else { 0 }
+}
+
+/// Size in bytes of the offset index following a chunk's header: one
+/// cumulative `u32` byte offset per element, plus a trailing entry
+/// marking the end of the last element, so element `i`'s value bytes
+/// span `offsets[i]..offsets[i + 1]`.
+///
+/// Shared by every reader of the container format (`World`'s
+/// sequential scan, `MmapIo`'s random access) so they agree on where
+/// the offset index ends and the dims table begins.
+pub const fn offsets_table_len(element_count: usize) -> usize {
+    (element_count + 1) * 4
+}
+
+/// Size in bytes of the dims table following the offset index: each
+/// element's `dims` dimensional coordinates (`u64` each), stored
+/// out-of-band from its encoded value bytes since
+/// [`Data::decode`](crate::Data::decode) takes `dims` separately and
+/// never reads them from the payload itself.
+pub const fn dims_table_len(element_count: usize, dims: usize) -> usize {
+    element_count * dims * 8
+}
+
+/// Per-chunk encryption metadata, present on a [`ChunkHeader`] only
+/// when the chunk was written through an encrypting `IoHandle` like
+/// `EncryptedIo`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkEncryption {
+    /// Identifies which key encrypted this chunk, so a world can be
+    /// re-keyed without losing the ability to decrypt chunks written
+    /// under an older key.
+    pub key_id: u32,
+    /// Nonce the chunk's keystream was derived from.
+    ///
+    /// Generated fresh every time a chunk is written (not derived from
+    /// its position or key-id) so rewriting a chunk never reuses the
+    /// same nonce under the same key, which would leak the XOR of the
+    /// two plaintexts.
+    pub nonce: [u8; 12],
+}
+
+/// Header written before a chunk's element payload.
+///
+/// Besides [`MAGIC`], this carries the container format's own version
+/// (independent of [`Data::VERSION`](crate::Data::VERSION), which only
+/// versions the encoding of the elements *inside* the payload), the
+/// chunk's dimensional position, how many elements it holds, a CRC32
+/// checksum of the payload so truncation or bit-rot is detected before
+/// `FromBytes` ever sees the bytes, and, for encrypted chunks, the
+/// [`ChunkEncryption`] needed to derive their keystream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkHeader {
+    /// Version of the container format that produced this chunk.
+    pub format_version: u8,
+    /// [`Data::VERSION`](crate::Data::VERSION) the payload was encoded with.
+    pub data_version: u32,
+    /// Dimensional position of this chunk.
+    pub pos: Vec<u64>,
+    /// Number of elements stored in the payload following this header.
+    pub len: u32,
+    /// CRC32 checksum of the *plaintext* payload following this header.
+    ///
+    /// Always the plaintext, even for an encrypted chunk: a reader never
+    /// sees raw ciphertext on its own, since `EncryptedIo::read_chunk`
+    /// decrypts the payload before handing bytes back, and `World`'s
+    /// scan validates whatever it receives from the configured
+    /// `IoHandle` - so the checksum must agree with that, not with
+    /// whatever happens to be stored on disk underneath it.
+    pub checksum: u32,
+    /// Present when the payload following this header is encrypted.
+    pub encryption: Option<ChunkEncryption>,
+}
+
+/// Current version of the container format itself.
+pub const FORMAT_VERSION: u8 = 1;
+
+impl ChunkHeader {
+    /// Builds a header for `pos`/`data_version` holding `element_count`
+    /// elements, computing the checksum over `payload`. Not encrypted;
+    /// chain [`with_encryption`](Self::with_encryption) to mark it as
+    /// such.
+    ///
+    /// `element_count` and `payload` are independent: `len` records how
+    /// many elements the offset/dims tables following this header
+    /// describe, which readers (`World`'s scan, `MmapIo`) need to frame
+    /// those tables correctly, while `payload` is only ever hashed for
+    /// the checksum. `World`'s scan checks that checksum against every
+    /// byte following the header (the offset index, the dims table and
+    /// the concatenated element values), so `payload` has to be exactly
+    /// those bytes.
+    pub fn new<const DIMS: usize>(
+        pos: [usize; DIMS],
+        data_version: u32,
+        element_count: usize,
+        payload: &[u8],
+    ) -> Self {
+        Self {
+            format_version: FORMAT_VERSION,
+            data_version,
+            pos: pos.iter().map(|&v| v as u64).collect(),
+            len: element_count as u32,
+            checksum: Self::checksum_of(payload),
+            encryption: None,
+        }
+    }
+
+    /// Marks this header as encrypted under `encryption`, so a decoding
+    /// reader knows which key and nonce to derive the keystream from.
+    pub fn with_encryption(mut self, encryption: ChunkEncryption) -> Self {
+        self.encryption = Some(encryption);
+        self
+    }
+
+    /// Encodes this header, including the leading [`MAGIC`] signature.
+    pub fn encode<B: BufMut>(&self, mut buf: B) {
+        buf.put_slice(&MAGIC);
+        buf.put_u8(self.format_version);
+        buf.put_u32(self.data_version);
+        buf.put_u32(self.pos.len() as u32);
+        for v in &self.pos {
+            buf.put_u64(*v);
+        }
+        buf.put_u32(self.len);
+        buf.put_u32(self.checksum);
+        match self.encryption {
+            Some(ChunkEncryption { key_id, nonce }) => {
+                buf.put_u8(1);
+                buf.put_u32(key_id);
+                buf.put_slice(&nonce);
+            }
+            None => buf.put_u8(0),
+        }
+    }
+
+    /// Decodes a header from the front of `buf`, validating the magic
+    /// signature first.
+    ///
+    /// Returns [`None`] if `buf` does not start with [`MAGIC`] or is too
+    /// short to hold one, meaning it is not (or is no longer) a valid
+    /// chunk container produced by this crate.
+    pub fn decode<B: Buf>(mut buf: B) -> Option<Self> {
+        if !Self::has_valid_magic(&buf) {
+            return None;
+        }
+        buf.advance(MAGIC.len());
+
+        if buf.remaining() < 9 {
+            return None;
+        }
+        let format_version = buf.get_u8();
+        let data_version = buf.get_u32();
+        let dims = buf.get_u32() as usize;
+
+        if buf.remaining() < dims * 8 + 8 {
+            return None;
+        }
+        let pos = (0..dims).map(|_| buf.get_u64()).collect();
+        let len = buf.get_u32();
+        let checksum = buf.get_u32();
+
+        if buf.remaining() < 1 {
+            return None;
+        }
+        let encryption = match buf.get_u8() {
+            0 => None,
+            _ => {
+                if buf.remaining() < 16 {
+                    return None;
+                }
+                let key_id = buf.get_u32();
+                let mut nonce = [0u8; 12];
+                buf.copy_to_slice(&mut nonce);
+                Some(ChunkEncryption { key_id, nonce })
+            }
+        };
+
+        Some(Self {
+            format_version,
+            data_version,
+            pos,
+            len,
+            checksum,
+            encryption,
+        })
+    }
+
+    /// Cheaply checks whether `buf` starts with a valid chunk magic
+    /// signature, without decoding the rest of the header.
+    ///
+    /// This is the intended backing implementation for
+    /// [`IoHandle::hint_is_valid`](crate::IoHandle::hint_is_valid).
+    pub fn has_valid_magic<B: Buf>(buf: &B) -> bool {
+        buf.chunk().starts_with(&MAGIC)
+    }
+
+    /// Computes the checksum of `payload` the same way it is computed
+    /// for the `checksum` field on write.
+    #[inline]
+    pub fn checksum_of(payload: &[u8]) -> u32 {
+        crc32fast::hash(payload)
+    }
+
+    /// Validates `payload` against this header's stored checksum,
+    /// returning [`Error::CorruptChunk`](crate::Error::CorruptChunk) on
+    /// mismatch.
+    pub fn validate(&self, payload: &[u8]) -> crate::Result<()> {
+        let actual_crc = Self::checksum_of(payload);
+        if actual_crc != self.checksum {
+            return Err(crate::Error::CorruptChunk {
+                expected_crc: self.checksum,
+                actual_crc,
+            });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+//! Transparent compression for chunk storage.
+
+use std::pin::Pin;
+
+use async_compression::{futures::bufread::ZstdDecoder, futures::write::ZstdEncoder, Level};
+use futures_lite::{io::BufReader, AsyncWrite};
+
+use crate::IoHandle;
+
+/// An [`IoHandle`] decorator that transparently zstd-compresses chunk
+/// payloads on write and decompresses them on read, so large sparse
+/// dimensional chunks don't cost their full uncompressed footprint on
+/// disk.
+///
+/// `FromBytes` is unaffected by this wrapper: it keeps reading plaintext
+/// element bytes, just from the decoded stream instead of the raw one.
+/// The decoded length must still match the element `len` computed from
+/// [`Dim`](crate::Dim), so the existing `UnexpectedEof` check in
+/// `FromBytes::poll` continues to guard against truncated chunks, now
+/// also catching compressed streams cut short.
+///
+/// `level` is applied on [`write_chunk`](IoHandle::write_chunk): the
+/// writer returned wraps whatever `inner` is willing to write in a
+/// [`ZstdEncoder`] at that level, so the bytes actually landing on disk
+/// (or in `inner`, for a further-wrapped handle) are the compressed
+/// form. Callers must `close()` the returned writer to flush the final
+/// zstd frame before the chunk is considered fully written.
+pub struct CompressedIo<H> {
+    inner: H,
+    level: Level,
+}
+
+impl<H> CompressedIo<H> {
+    /// Wraps `inner`, compressing chunks written through this handle at
+    /// the given `level`.
+    pub fn new(inner: H, level: Level) -> Self {
+        Self { inner, level }
+    }
+
+    /// The compression level new chunks are written with.
+    #[inline]
+    pub fn level(&self) -> Level {
+        self.level
+    }
+
+    /// Unwraps this decorator, discarding the configured level.
+    #[inline]
+    pub fn into_inner(self) -> H {
+        self.inner
+    }
+}
+
+impl<H: IoHandle> IoHandle for CompressedIo<H> {
+    type Read<'a>
+        = ZstdDecoder<BufReader<H::Read<'a>>>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn hint_is_valid(&self, pos: &[usize]) -> bool {
+        self.inner.hint_is_valid(pos)
+    }
+
+    async fn read_chunk<const DIMS: usize>(
+        &self,
+        pos: [usize; DIMS],
+    ) -> std::io::Result<(u32, Self::Read<'_>)> {
+        let (version, read) = self.inner.read_chunk(pos).await?;
+        Ok((version, ZstdDecoder::new(BufReader::new(read))))
+    }
+
+    async fn write_chunk<const DIMS: usize>(
+        &self,
+        pos: [usize; DIMS],
+        data_version: u32,
+    ) -> std::io::Result<Pin<Box<dyn AsyncWrite + Unpin + Send + Sync + '_>>> {
+        let write = self.inner.write_chunk(pos, data_version).await?;
+        Ok(Box::pin(ZstdEncoder::with_quality(write, self.level)))
+    }
+}
@@ -0,0 +1,203 @@
+//! Encryption-at-rest for chunk storage.
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use chacha20::{
+    cipher::{KeyIvInit, StreamCipher},
+    ChaCha20,
+};
+use futures_lite::{AsyncReadExt, AsyncWrite};
+use rand::RngCore;
+
+use crate::{chunk::ChunkEncryption, IoHandle};
+
+/// An [`IoHandle`] decorator that encrypts chunk payloads at rest with
+/// a ChaCha20 keystream, so worlds persisted to untrusted storage stay
+/// confidential.
+///
+/// The nonce and a key-id are stored in the
+/// [`ChunkHeader`](crate::ChunkHeader) itself (as
+/// [`ChunkEncryption`](crate::chunk::ChunkEncryption)), never
+/// recomputed from the chunk's position: a nonce derived solely from
+/// `pos` would be reused every time that chunk is rewritten under the
+/// same key, leaking the XOR of the old and new plaintexts. Instead,
+/// [`write_chunk`](IoHandle::write_chunk) generates a fresh random
+/// nonce on every write and stores it in the header; reading only ever
+/// trusts whatever nonce the header says the payload was encrypted
+/// with.
+///
+/// Because the header carries the nonce and key-id in the clear, it is
+/// never itself encrypted — only the payload following it is. This
+/// keeps the plaintext `MAGIC`/header readable by `IoHandle::hint_is_valid`
+/// and by `ChunkHeader::decode` without needing the key first.
+pub struct EncryptedIo<H> {
+    inner: H,
+    key: [u8; 32],
+    key_id: u32,
+}
+
+impl<H> EncryptedIo<H> {
+    /// Wraps `inner`, encrypting/decrypting chunk payloads through `key`.
+    ///
+    /// `key_id` must match the `key_id` stored in a chunk's
+    /// [`ChunkEncryption`] for this handle to decrypt it; a mismatch
+    /// (or a chunk with no encryption metadata at all) fails with
+    /// [`Error::Decrypt`](crate::Error::Decrypt).
+    pub fn new(inner: H, key: [u8; 32], key_id: u32) -> Self {
+        Self { inner, key, key_id }
+    }
+}
+
+impl<H: IoHandle> IoHandle for EncryptedIo<H> {
+    type Read<'a>
+        = futures_lite::io::Cursor<Vec<u8>>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn hint_is_valid(&self, pos: &[usize]) -> bool {
+        self.inner.hint_is_valid(pos)
+    }
+
+    async fn read_chunk<const DIMS: usize>(
+        &self,
+        pos: [usize; DIMS],
+    ) -> std::io::Result<(u32, Self::Read<'_>)> {
+        let (version, mut inner) = self.inner.read_chunk(pos).await?;
+
+        // The header is written in plaintext (see module docs), so the
+        // whole raw chunk is read first and only the payload past it
+        // is run through the keystream, instead of decrypting from
+        // byte 0 and corrupting the header.
+        let mut raw = Vec::new();
+        inner.read_to_end(&mut raw).await?;
+
+        let header = crate::ChunkHeader::decode(&raw[..])
+            .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidData, "bad chunk header"))?;
+        let header_len = crate::chunk::encoded_len(header.pos.len(), header.encryption.is_some());
+
+        let ChunkEncryption { key_id, nonce } = header
+            .encryption
+            .ok_or_else(|| std::io::Error::other(crate::Error::Decrypt))?;
+        if key_id != self.key_id {
+            return Err(std::io::Error::other(crate::Error::Decrypt));
+        }
+
+        let payload = raw.get_mut(header_len..).ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "chunk shorter than its header claims",
+            )
+        })?;
+        ChaCha20::new((&self.key).into(), (&nonce).into()).apply_keystream(payload);
+
+        Ok((version, futures_lite::io::Cursor::new(raw)))
+    }
+
+    async fn write_chunk<const DIMS: usize>(
+        &self,
+        pos: [usize; DIMS],
+        data_version: u32,
+    ) -> std::io::Result<Pin<Box<dyn AsyncWrite + Unpin + Send + Sync + '_>>> {
+        let inner = self.inner.write_chunk(pos, data_version).await?;
+        Ok(Box::pin(EncryptingWrite {
+            inner,
+            key: self.key,
+            key_id: self.key_id,
+            buf: Vec::new(),
+            flushed: None,
+        }))
+    }
+}
+
+/// Buffers a whole chunk's bytes (header, written in plaintext by the
+/// caller, followed by its payload), then on close generates a fresh
+/// nonce, marks the re-encoded header as encrypted with it, XORs the
+/// payload with the resulting keystream, and forwards the combined
+/// bytes to `inner`.
+///
+/// The whole chunk is buffered rather than encrypted as it streams in
+/// because the caller writes one contiguous byte stream with no
+/// signal for where the header ends - this decorator has to decode it
+/// first to know where the payload (and thus the keystream) begins,
+/// same as [`EncryptedIo::read_chunk`] buffers on the way in.
+struct EncryptingWrite<'a> {
+    inner: Pin<Box<dyn AsyncWrite + Unpin + Send + Sync + 'a>>,
+    key: [u8; 32],
+    key_id: u32,
+    buf: Vec<u8>,
+    flushed: Option<(Vec<u8>, usize)>,
+}
+
+impl EncryptingWrite<'_> {
+    fn encrypt(&mut self) -> std::io::Result<Vec<u8>> {
+        let mut raw = std::mem::take(&mut self.buf);
+        let header = crate::ChunkHeader::decode(&raw[..]).ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, "bad chunk header")
+        })?;
+        let plain_header_len = crate::chunk::encoded_len(header.pos.len(), false);
+
+        let mut nonce = [0u8; 12];
+        rand::thread_rng().fill_bytes(&mut nonce);
+        let header = header.with_encryption(ChunkEncryption {
+            key_id: self.key_id,
+            nonce,
+        });
+
+        let mut payload = raw.split_off(plain_header_len);
+        ChaCha20::new((&self.key).into(), (&nonce).into()).apply_keystream(&mut payload);
+
+        let mut out = Vec::with_capacity(
+            crate::chunk::encoded_len(header.pos.len(), true) + payload.len(),
+        );
+        header.encode(&mut out);
+        out.extend_from_slice(&payload);
+        Ok(out)
+    }
+}
+
+impl AsyncWrite for EncryptingWrite<'_> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        data: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        self.get_mut().buf.extend_from_slice(data);
+        Poll::Ready(Ok(data.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+
+        if this.flushed.is_none() {
+            match this.encrypt() {
+                Ok(bytes) => this.flushed = Some((bytes, 0)),
+                Err(err) => return Poll::Ready(Err(err)),
+            }
+        }
+
+        let (bytes, written) = this.flushed.as_mut().unwrap();
+        while *written < bytes.len() {
+            match this.inner.as_mut().poll_write(cx, &bytes[*written..]) {
+                Poll::Ready(Ok(0)) => {
+                    return Poll::Ready(Err(std::io::Error::new(
+                        std::io::ErrorKind::WriteZero,
+                        "failed to write whole encrypted chunk",
+                    )))
+                }
+                Poll::Ready(Ok(n)) => *written += n,
+                Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        this.inner.as_mut().poll_close(cx)
+    }
+}
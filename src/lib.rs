@@ -7,16 +7,28 @@ mod range;
 /// Module containing in-memory IO handlers for testing.
 pub mod mem_io_handle;
 
+/// Transparent zstd compression layer for any [`IoHandle`].
+pub mod compressed_io;
+
+/// Memory-mapped IO handler with O(1) intra-chunk random access.
+#[cfg(feature = "mmap")]
+pub mod mmap_io;
+
+/// Encryption-at-rest layer for any [`IoHandle`].
+pub mod encrypted_io;
+
+mod chunk;
 mod macros;
 mod world;
 
 #[cfg(test)]
 mod tests;
 
-use std::ops::Deref;
+use std::{ops::Deref, pin::Pin};
 
-use futures_lite::{AsyncRead, Future};
+use futures_lite::{AsyncRead, AsyncWrite, Future};
 
+pub use chunk::ChunkHeader;
 pub use world::{iter::Iter, iter::Lazy, Chunk, Chunks, Dim, Select, World};
 
 #[doc(hidden)]
@@ -94,6 +106,52 @@ pub trait IoHandle: Send + Sync {
         &self,
         pos: [usize; DIMS],
     ) -> impl Future<Output = std::io::Result<(u32, Self::Read<'_>)>> + Send + Sync;
+
+    /// Gets a seekable reader over the chunk's payload at `pos`, plus the
+    /// byte offset and length of a single element within it, using an
+    /// offset index persisted alongside the chunk. Callers seek the
+    /// reader to `offset` and read exactly `len` bytes, turning a point
+    /// lookup into one seek and one read instead of streaming every
+    /// element up to it.
+    ///
+    /// Returns `Ok(None)` when this handler has no such index, which is
+    /// the default; callers should then fall back to
+    /// [`read_chunk`](Self::read_chunk) and a sequential scan.
+    fn read_element<const DIMS: usize>(
+        &self,
+        pos: [usize; DIMS],
+        index: usize,
+    ) -> impl Future<Output = std::io::Result<Option<(u32, Self::Read<'_>, u64, usize)>>> + Send + Sync
+    where
+        for<'a> Self::Read<'a>: futures_lite::AsyncSeek,
+    {
+        let _ = (pos, index);
+        async { Ok(None) }
+    }
+
+    /// Opens a writer that replaces the chunk at `pos` with whatever is
+    /// written to it, recorded under `data_version`, once the returned
+    /// writer is closed.
+    ///
+    /// Returns `Err` with [`io::ErrorKind::Unsupported`] by default.
+    /// Decorators (e.g. [`CompressedIo`](crate::compressed_io::CompressedIo))
+    /// open their inner handle's writer and transform the bytes written
+    /// through it, so over an inner handle that keeps this default they
+    /// fail the same way.
+    fn write_chunk<const DIMS: usize>(
+        &self,
+        pos: [usize; DIMS],
+        data_version: u32,
+    ) -> impl Future<Output = std::io::Result<Pin<Box<dyn AsyncWrite + Unpin + Send + Sync + '_>>>> + Send + Sync
+    {
+        let _ = (pos, data_version);
+        async {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "this IoHandle does not support writing chunks",
+            ))
+        }
+    }
 }
 
 impl<P, T> IoHandle for P
@@ -103,6 +161,18 @@ where
 {
     type Read<'a> = T::Read<'a> where Self: 'a;
 
+    #[inline]
+    fn read_element<const DIMS: usize>(
+        &self,
+        pos: [usize; DIMS],
+        index: usize,
+    ) -> impl Future<Output = std::io::Result<Option<(u32, Self::Read<'_>, u64, usize)>>>
+    where
+        for<'a> Self::Read<'a>: futures_lite::AsyncSeek,
+    {
+        self.deref().read_element(pos, index)
+    }
+
     #[inline]
     fn hint_is_valid(&self, pos: &[usize]) -> bool {
         self.deref().hint_is_valid(pos)
@@ -115,6 +185,16 @@ where
     ) -> impl Future<Output = std::io::Result<(u32, Self::Read<'_>)>> {
         self.deref().read_chunk(pos)
     }
+
+    #[inline]
+    fn write_chunk<const DIMS: usize>(
+        &self,
+        pos: [usize; DIMS],
+        data_version: u32,
+    ) -> impl Future<Output = std::io::Result<Pin<Box<dyn AsyncWrite + Unpin + Send + Sync + '_>>>>
+    {
+        self.deref().write_chunk(pos, data_version)
+    }
 }
 
 /// Represents error variants produced by this crate.
@@ -140,6 +220,19 @@ pub enum Error {
         /// The value.
         value: u64,
     },
+    /// A chunk's payload did not match the checksum recorded in its
+    /// [`ChunkHeader`].
+    #[error("corrupt chunk: expected crc {expected_crc:08x}, got {actual_crc:08x}")]
+    CorruptChunk {
+        /// CRC32 recorded in the chunk header.
+        expected_crc: u32,
+        /// CRC32 actually computed from the payload.
+        actual_crc: u32,
+    },
+    /// Failed to decrypt a chunk's payload, e.g. the key-id stored in
+    /// its header does not match any available key.
+    #[error("failed to decrypt chunk")]
+    Decrypt,
 }
 
 /// Type alias for result produced by this crate.
@@ -0,0 +1,216 @@
+//! Memory-mapped [`IoHandle`] with O(1) intra-chunk random access.
+//!
+//! Unlike the streaming handlers, `MmapIo` reads the small offset
+//! index that follows each chunk file's header, letting [`Lazy::seek`]
+//! seek straight to one element instead of streaming the whole chunk.
+//! It only reads chunk files: its `write_chunk` is `IoHandle`'s
+//! default, which returns `Unsupported`.
+//!
+//! Gated behind the `mmap` feature.
+
+use std::{
+    collections::HashMap,
+    io,
+    path::{Path, PathBuf},
+    sync::RwLock,
+};
+
+use memmap2::Mmap;
+
+use crate::IoHandle;
+
+/// Cursor over a memory-mapped chunk, implementing [`AsyncRead`] and
+/// [`AsyncSeek`](futures_lite::AsyncSeek) within a fixed `start..end`
+/// byte range.
+pub struct MmapCursor {
+    mmap: std::sync::Arc<Mmap>,
+    start: usize,
+    pos: usize,
+    end: usize,
+}
+
+impl futures_lite::AsyncRead for MmapCursor {
+    fn poll_read(
+        self: std::pin::Pin<&mut Self>,
+        _cx: &mut std::task::Context<'_>,
+        buf: &mut [u8],
+    ) -> std::task::Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let available = &this.mmap[this.pos..this.end];
+        let len = available.len().min(buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        this.pos += len;
+        std::task::Poll::Ready(Ok(len))
+    }
+}
+
+impl futures_lite::AsyncSeek for MmapCursor {
+    fn poll_seek(
+        self: std::pin::Pin<&mut Self>,
+        _cx: &mut std::task::Context<'_>,
+        pos: io::SeekFrom,
+    ) -> std::task::Poll<io::Result<u64>> {
+        let this = self.get_mut();
+        let target = match pos {
+            io::SeekFrom::Start(n) => this.start as i64 + n as i64,
+            io::SeekFrom::Current(n) => this.pos as i64 + n,
+            io::SeekFrom::End(n) => this.end as i64 + n,
+        };
+
+        if target < this.start as i64 || target > this.end as i64 {
+            return std::task::Poll::Ready(Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "seek target out of bounds for this element",
+            )));
+        }
+
+        this.pos = target as usize;
+        std::task::Poll::Ready(Ok((this.pos - this.start) as u64))
+    }
+}
+
+/// An [`IoHandle`] that memory-maps chunk files from a directory,
+/// enabling random access to individual elements via a persisted
+/// offset index instead of always streaming the whole chunk.
+///
+/// Each chunk file starts with a [`ChunkHeader`](crate::ChunkHeader),
+/// followed by the offset index (see [`chunk::offsets_table_len`]) and
+/// the dims table (see [`chunk::dims_table_len`]), then the
+/// concatenated per-element value bytes: element `i`'s dims are
+/// `dims_table[i]` and its value bytes span `offsets[i]..offsets[i + 1]`
+/// within the value region, matching exactly what `World`'s sequential
+/// chunk scan expects from the same file.
+pub struct MmapIo {
+    dir: PathBuf,
+    maps: RwLock<HashMap<PathBuf, std::sync::Arc<Mmap>>>,
+}
+
+impl MmapIo {
+    /// Opens chunk files from `dir`, memory-mapping them lazily on
+    /// first access.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            maps: RwLock::new(HashMap::new()),
+        }
+    }
+
+    fn path_of<const DIMS: usize>(&self, pos: [usize; DIMS]) -> PathBuf {
+        let name = pos
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<_>>()
+            .join("_");
+        self.dir.join(name)
+    }
+
+    fn mmap_of(&self, path: &Path) -> io::Result<std::sync::Arc<Mmap>> {
+        if let Some(mmap) = self.maps.read().unwrap().get(path) {
+            return Ok(mmap.clone());
+        }
+
+        let file = std::fs::File::open(path)?;
+        // Safety: chunk files are only ever replaced wholesale by
+        // `World`, never truncated or mutated in place while mapped.
+        let mmap = std::sync::Arc::new(unsafe { Mmap::map(&file)? });
+        self.maps
+            .write()
+            .unwrap()
+            .insert(path.to_path_buf(), mmap.clone());
+        Ok(mmap)
+    }
+
+    /// Reads the offset index at the head of a mapped chunk, located
+    /// right after `header_len` header bytes.
+    fn offsets(mmap: &Mmap, header_len: usize, element_count: usize) -> Vec<u32> {
+        let table_len = crate::chunk::offsets_table_len(element_count);
+        let bytes = &mmap[header_len..header_len + table_len];
+        bytes
+            .chunks_exact(4)
+            .map(|c| u32::from_le_bytes(c.try_into().unwrap()))
+            .collect()
+    }
+}
+
+impl IoHandle for MmapIo {
+    type Read<'a> = MmapCursor;
+
+    fn hint_is_valid(&self, pos: &[usize]) -> bool {
+        let path = self.path_of_dyn(pos);
+        let Ok(mmap) = self.mmap_of(&path) else {
+            return false;
+        };
+        let buf: &[u8] = &mmap[..];
+        crate::ChunkHeader::has_valid_magic(&buf)
+    }
+
+    async fn read_chunk<const DIMS: usize>(
+        &self,
+        pos: [usize; DIMS],
+    ) -> io::Result<(u32, Self::Read<'_>)> {
+        let path = self.path_of(pos);
+        let mmap = self.mmap_of(&path)?;
+        let header = crate::ChunkHeader::decode(&mmap[..])
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "bad chunk header"))?;
+        // The whole file, header included, is handed back: `World`'s
+        // scan decodes and validates the header and tables itself.
+        Ok((
+            header.data_version,
+            MmapCursor {
+                start: 0,
+                end: mmap.len(),
+                pos: 0,
+                mmap,
+            },
+        ))
+    }
+
+    async fn read_element<const DIMS: usize>(
+        &self,
+        pos: [usize; DIMS],
+        index: usize,
+    ) -> io::Result<Option<(u32, Self::Read<'_>, u64, usize)>> {
+        let path = self.path_of(pos);
+        let mmap = self.mmap_of(&path)?;
+        let header = match crate::ChunkHeader::decode(&mmap[..]) {
+            Some(header) => header,
+            None => return Ok(None),
+        };
+        let header_len = crate::chunk::encoded_len(header.pos.len(), header.encryption.is_some());
+        let offsets = Self::offsets(&mmap, header_len, header.len as usize);
+
+        let Some((&start, &end)) = offsets.get(index).zip(offsets.get(index + 1)) else {
+            return Ok(None);
+        };
+        let payload_start = header_len
+            + crate::chunk::offsets_table_len(header.len as usize)
+            + crate::chunk::dims_table_len(header.len as usize, DIMS);
+
+        // The returned cursor spans the whole remaining payload rather
+        // than just this element, so the caller performs a real seek by
+        // `offset` before reading `len` bytes instead of receiving an
+        // already-positioned reader.
+        Ok(Some((
+            header.data_version,
+            MmapCursor {
+                start: payload_start,
+                pos: payload_start,
+                end: mmap.len(),
+                mmap,
+            },
+            start as u64,
+            (end - start) as usize,
+        )))
+    }
+}
+
+impl MmapIo {
+    fn path_of_dyn(&self, pos: &[usize]) -> PathBuf {
+        let name = pos
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<_>>()
+            .join("_");
+        self.dir.join(name)
+    }
+}
@@ -0,0 +1,132 @@
+//! Unit tests for the on-disk chunk container format.
+//!
+//! `World`'s own read/write path lives in modules not present in this
+//! checkout, so these stick to what's self-contained here: the header
+//! encode/decode round-trip, checksum validation, and the offset/dims
+//! table sizing math that `world::iter::fetch_chunk` and `MmapIo` both
+//! rely on to agree on a chunk's layout.
+
+use crate::{
+    chunk::{dims_table_len, encoded_len, offsets_table_len, ChunkEncryption, ChunkHeader},
+    Error,
+};
+
+#[test]
+fn header_round_trips_without_encryption() {
+    let payload = b"hello chunk";
+    let header = ChunkHeader::new([1usize, 2], 7, 3, payload);
+
+    let mut buf = Vec::new();
+    header.encode(&mut buf);
+    assert_eq!(buf.len(), encoded_len(header.pos.len(), false));
+
+    let decoded = ChunkHeader::decode(&buf[..]).expect("valid header");
+    assert_eq!(decoded, header);
+}
+
+#[test]
+fn header_round_trips_with_encryption() {
+    let payload = b"super secret";
+    let header = ChunkHeader::new([5usize], 1, 2, payload).with_encryption(ChunkEncryption {
+        key_id: 42,
+        nonce: [9u8; 12],
+    });
+
+    let mut buf = Vec::new();
+    header.encode(&mut buf);
+    assert_eq!(buf.len(), encoded_len(header.pos.len(), true));
+
+    let decoded = ChunkHeader::decode(&buf[..]).expect("valid header");
+    assert_eq!(decoded, header);
+}
+
+#[test]
+fn decode_rejects_bad_magic() {
+    assert!(ChunkHeader::decode(&[0u8; 32][..]).is_none());
+
+    let header = ChunkHeader::new([0usize], 0, 0, &[]);
+    let mut encoded = Vec::new();
+    header.encode(&mut encoded);
+    encoded[0] ^= 0xFF;
+    assert!(ChunkHeader::decode(&encoded[..]).is_none());
+}
+
+#[test]
+fn validate_rejects_a_tampered_payload() {
+    let header = ChunkHeader::new([0usize], 0, 1, b"original");
+    let err = header.validate(b"tampered!").unwrap_err();
+
+    match err {
+        Error::CorruptChunk {
+            expected_crc,
+            actual_crc,
+        } => {
+            assert_eq!(expected_crc, ChunkHeader::checksum_of(b"original"));
+            assert_ne!(expected_crc, actual_crc);
+        }
+        other => panic!("expected CorruptChunk, got {other:?}"),
+    }
+}
+
+/// Builds the offsets/dims/values region exactly as `fetch_chunk` and
+/// `MmapIo::read_element` expect it, then walks it back apart using the
+/// same slicing both of them do — the layout invariant a byte-count bug
+/// in either helper would break.
+#[test]
+fn offset_and_dims_tables_frame_the_payload_consistently() {
+    const DIMS: usize = 2;
+    let elements: &[([u64; DIMS], &[u8])] = &[([1, 2], b"aa"), ([3, 4], b"bbb"), ([5, 6], b"c")];
+
+    let mut values = Vec::new();
+    let mut offsets = vec![0u32];
+    for (_, bytes) in elements {
+        values.extend_from_slice(bytes);
+        offsets.push(values.len() as u32);
+    }
+
+    let mut dims_buf = Vec::new();
+    for (dims, _) in elements {
+        for d in dims {
+            dims_buf.extend_from_slice(&d.to_le_bytes());
+        }
+    }
+
+    let mut offsets_buf = Vec::new();
+    for o in &offsets {
+        offsets_buf.extend_from_slice(&o.to_le_bytes());
+    }
+
+    assert_eq!(offsets_buf.len(), offsets_table_len(elements.len()));
+    assert_eq!(dims_buf.len(), dims_table_len(elements.len(), DIMS));
+
+    let mut rest = Vec::new();
+    rest.extend_from_slice(&offsets_buf);
+    rest.extend_from_slice(&dims_buf);
+    rest.extend_from_slice(&values);
+
+    let offsets_len = offsets_table_len(elements.len());
+    let dims_len = dims_table_len(elements.len(), DIMS);
+
+    let parsed_offsets: Vec<u32> = rest[..offsets_len]
+        .chunks_exact(4)
+        .map(|c| u32::from_le_bytes(c.try_into().unwrap()))
+        .collect();
+    let parsed_dims = &rest[offsets_len..offsets_len + dims_len];
+    let parsed_values = &rest[offsets_len + dims_len..];
+
+    assert_eq!(parsed_offsets, offsets);
+    assert_eq!(parsed_dims, &dims_buf[..]);
+
+    for (i, (dims, bytes)) in elements.iter().enumerate() {
+        let mut got_dims = [0u64; DIMS];
+        let raw_dims = &parsed_dims[i * DIMS * 8..(i + 1) * DIMS * 8];
+        for (d, raw) in got_dims.iter_mut().zip(raw_dims.chunks_exact(8)) {
+            *d = u64::from_le_bytes(raw.try_into().unwrap());
+        }
+        assert_eq!(&got_dims, dims);
+
+        let start = parsed_offsets[i] as usize;
+        let end = parsed_offsets[i + 1] as usize;
+        assert_eq!(&parsed_values[start..end], *bytes);
+    }
+}
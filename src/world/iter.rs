@@ -1,4 +1,5 @@
 use std::{
+    collections::VecDeque,
     future::Future,
     marker::PhantomData,
     pin::Pin,
@@ -10,7 +11,7 @@ use std::{
 };
 
 use bytes::BufMut;
-use futures_lite::{ready, stream::CountFuture, AsyncRead, Stream};
+use futures_lite::{ready, stream::CountFuture, AsyncRead, AsyncReadExt, Stream};
 use pin_project_lite::pin_project;
 
 use crate::{Data, IoHandle};
@@ -19,7 +20,14 @@ use super::{select::Shape, World};
 
 enum ReadType<const DIMS: usize> {
     Mem([usize; DIMS]),
-    Io(usize),
+    Io { version: u32, len: usize },
+    /// Built only by [`Lazy::seek`], which has already sought the
+    /// reader held in `read` to `offset` (a bound `get_or_init` can't
+    /// carry generically, since most `ReadType`s need no `AsyncSeek`
+    /// at all) — so by the time `get_or_init` runs, only `len` bytes
+    /// need to be read. `offset` is kept for error context if that
+    /// read comes up short.
+    IoSeek { version: u32, offset: u64, len: usize },
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -35,6 +43,10 @@ pub enum Error {
         expected: usize,
         current: Option<usize>,
     },
+    #[error("{0}")]
+    Chunk(crate::Error),
+    #[error("selection scan was aborted")]
+    Aborted,
 }
 
 /// A type polls value lazily and immutably.
@@ -43,11 +55,12 @@ pub struct Lazy<'a, T: Data, const DIMS: usize, Io: IoHandle> {
     dims: [u64; DIMS],
     read_type: ReadType<DIMS>,
     value: OnceLock<Value<'a, T, DIMS>>,
-    read: std::sync::Mutex<Option<Pin<&'a mut Io::Read>>>,
+    read: std::sync::Mutex<Option<Pin<Box<Io::Read<'a>>>>>,
 
     state: LazyCheckState,
 }
 
+#[derive(Clone)]
 struct LazyCheckState {
     current: Weak<AtomicUsize>,
     expected: usize,
@@ -65,9 +78,37 @@ impl<T: Data, const DIMS: usize, Io: IoHandle> Lazy<'_, T, DIMS, Io> {
         &self.dims
     }
 
+    /// Checks this `Lazy`'s generation snapshot against the world's
+    /// current one, so a structural change concurrent with the scan
+    /// that produced this `Lazy` is still caught.
+    ///
+    /// Runs on every `get_or_init` call, even once `value` is already
+    /// resolved: `ChunkIter` decodes whole chunks eagerly and stashes
+    /// the result straight into `value`, so without this check here, a
+    /// pre-decoded `Lazy` would never re-validate and `IterUpdated`
+    /// could never fire for it.
+    fn check_gen(&self) -> Result<(), Error> {
+        let current = self
+            .state
+            .current
+            .upgrade()
+            .map(|v| v.load(atomic::Ordering::Acquire));
+
+        if current != Some(self.state.expected) {
+            return Err(Error::IterUpdated {
+                expected: self.state.expected,
+                current,
+            });
+        }
+
+        Ok(())
+    }
+
     /// Gets the value inside this initializer or initialize it
     /// if uninitialized.
     pub async fn get_or_init(&self) -> Result<&T, Error> {
+        self.check_gen()?;
+
         if let Some(value) = self.value.get() {
             return match value {
                 Value::Ref(val) => Ok(&*val),
@@ -90,33 +131,26 @@ impl<T: Data, const DIMS: usize, Io: IoHandle> Lazy<'_, T, DIMS, Io> {
                     unreachable!()
                 })
             }
-            ReadType::Io(len) => {
-                {
-                    let current = self
-                        .state
-                        .current
-                        .upgrade()
-                        .map(|v| v.load(atomic::Ordering::Acquire));
-
-                    if current != Some(self.state.expected) {
-                        return Err(Error::IterUpdated {
-                            expected: self.state.expected,
-                            current,
-                        });
-                    }
+            ReadType::Io { version, len } | ReadType::IoSeek { version, len, .. } => {
+                let read = self.read.lock().unwrap().take().unwrap();
+                let decoded = FromBytes {
+                    _world: self.world,
+                    read,
+                    dims: &self.dims,
+                    version,
+                    len,
+                    buf: None,
                 }
-
-                let _ = self.value.set(Value::Direct(
-                    FromBytes {
-                        _world: self.world,
-                        read: self.read.lock().unwrap().take().unwrap(),
-                        dims: &self.dims,
-                        len,
-                        buf: None,
-                    }
-                    .await
-                    .map_err(Error::Io)?,
-                ));
+                .await
+                .map_err(|err| match self.read_type {
+                    ReadType::IoSeek { offset, .. } => futures_lite::io::Error::new(
+                        err.kind(),
+                        format!("{err} (seeking element at payload offset {offset})"),
+                    ),
+                    _ => err,
+                })
+                .map_err(Error::Io)?;
+                let _ = self.value.set(Value::Direct(decoded));
 
                 Ok(if let Some(Value::Direct(val)) = self.value.get() {
                     val
@@ -126,12 +160,58 @@ impl<T: Data, const DIMS: usize, Io: IoHandle> Lazy<'_, T, DIMS, Io> {
             }
         }
     }
+
+    /// Builds a `Lazy` for element `index` of the chunk at `pos`, using
+    /// [`IoHandle::read_element`]'s offset index to seek directly to
+    /// it — an O(1) point lookup instead of streaming the whole chunk.
+    ///
+    /// Returns `Ok(None)` when the handler has no offset index for this
+    /// chunk, in which case callers should fall back to streaming the
+    /// chunk via `ReadType::Io`.
+    ///
+    /// The seek itself happens here, eagerly, rather than inside
+    /// `get_or_init`: it needs `Io::Read: AsyncSeek`, a bound
+    /// `get_or_init` can't carry since most `ReadType`s (`Mem`, `Io`)
+    /// never need it.
+    pub(crate) async fn seek(
+        world: &'a World<T, DIMS, Io>,
+        pos: [usize; DIMS],
+        index: usize,
+        dims: [u64; DIMS],
+        state: LazyCheckState,
+    ) -> std::io::Result<Option<Self>>
+    where
+        for<'r> Io::Read<'r>: futures_lite::AsyncSeek,
+    {
+        let Some((version, read, offset, len)) = world.io().read_element(pos, index).await?
+        else {
+            return Ok(None);
+        };
+
+        let mut read = Box::pin(read);
+        futures_lite::AsyncSeekExt::seek(&mut read, futures_lite::io::SeekFrom::Start(offset))
+            .await?;
+
+        Ok(Some(Self {
+            world,
+            dims,
+            read_type: ReadType::IoSeek {
+                version,
+                offset,
+                len,
+            },
+            value: OnceLock::new(),
+            read: std::sync::Mutex::new(Some(read)),
+            state,
+        }))
+    }
 }
 
 struct FromBytes<'a, T: Data, const DIMS: usize, Io: IoHandle> {
     _world: &'a World<T, DIMS, Io>,
-    read: Pin<&'a mut Io::Read>,
+    read: Pin<Box<Io::Read<'a>>>,
     dims: &'a [u64; DIMS],
+    version: u32,
     len: usize,
     buf: Option<bytes::BytesMut>,
 }
@@ -159,7 +239,7 @@ impl<T: Data, const DIMS: usize, Io: IoHandle> Future for FromBytes<'_, T, DIMS,
                 unreachable!()
             };
             let buf = buf.freeze();
-            Poll::Ready(T::decode(this.dims, buf))
+            Poll::Ready(T::decode(this.version, this.dims, buf))
         } else {
             let mut buf = bytes::BytesMut::with_capacity(this.len);
             buf.put_bytes(0, this.len);
@@ -169,36 +249,320 @@ impl<T: Data, const DIMS: usize, Io: IoHandle> Future for FromBytes<'_, T, DIMS,
     }
 }
 
+/// Builds the `Error::Io` a malformed chunk's framing is rejected with,
+/// instead of panicking on a short slice.
+fn truncated(what: &str) -> Error {
+    Error::Io(futures_lite::io::Error::new(
+        futures_lite::io::ErrorKind::UnexpectedEof,
+        format!("chunk payload too short to hold {what}"),
+    ))
+}
+
+/// Fetches and fully decodes the chunk at `pos`: reads the raw bytes
+/// from the handler, validates the [`ChunkHeader`](crate::ChunkHeader)
+/// against its checksum, then decodes each of its elements.
+///
+/// The payload following the header is laid out exactly as `MmapIo`
+/// expects it (see its module docs): an offset index
+/// ([`chunk::offsets_table_len`](crate::chunk::offsets_table_len)), a
+/// dims table
+/// ([`chunk::dims_table_len`](crate::chunk::dims_table_len)), then the
+/// concatenated per-element value bytes, with element `i`'s value
+/// bytes spanning `offsets[i]..offsets[i + 1]` — the same slice
+/// [`FromBytes`] would read via [`Lazy::seek`] for a point lookup, with
+/// no per-element length prefix and no dims bytes mixed into it.
+///
+/// This is the unit of work `ChunkIter` drives and `Iter` runs several
+/// of concurrently.
+async fn fetch_chunk<T: Data, const DIMS: usize, Io: IoHandle>(
+    world: &World<T, DIMS, Io>,
+    pos: [usize; DIMS],
+) -> Result<([usize; DIMS], u32, Vec<([u64; DIMS], T)>), Error> {
+    // `World` owns the configured `IoHandle` behind this accessor.
+    let (_version, mut read) = world.io().read_chunk(pos).await.map_err(Error::Io)?;
+
+    let mut raw = Vec::new();
+    read.read_to_end(&mut raw).await.map_err(Error::Io)?;
+
+    let header = crate::ChunkHeader::decode(&raw[..]).ok_or_else(|| {
+        Error::Io(futures_lite::io::Error::new(
+            futures_lite::io::ErrorKind::InvalidData,
+            "missing or invalid chunk header",
+        ))
+    })?;
+    let header_len = crate::chunk::encoded_len(header.pos.len(), header.encryption.is_some());
+    let rest = raw.get(header_len..).ok_or_else(|| truncated("its header"))?;
+    header.validate(rest).map_err(Error::Chunk)?;
+
+    let count = header.len as usize;
+    let offsets_len = crate::chunk::offsets_table_len(count);
+    let dims_len = crate::chunk::dims_table_len(count, DIMS);
+
+    let offsets_buf = rest.get(..offsets_len).ok_or_else(|| truncated("its offset index"))?;
+    let offsets: Vec<u32> = offsets_buf
+        .chunks_exact(4)
+        .map(|c| u32::from_le_bytes(c.try_into().unwrap()))
+        .collect();
+
+    let dims_buf = rest
+        .get(offsets_len..offsets_len + dims_len)
+        .ok_or_else(|| truncated("its dims table"))?;
+    let values = rest
+        .get(offsets_len + dims_len..)
+        .ok_or_else(|| truncated("its element values"))?;
+
+    let mut items = Vec::with_capacity(count);
+    for i in 0..count {
+        let mut dims = [0u64; DIMS];
+        let dims_buf = dims_buf
+            .get(i * DIMS * 8..(i + 1) * DIMS * 8)
+            .ok_or_else(|| truncated("this element's dims"))?;
+        for (d, raw) in dims.iter_mut().zip(dims_buf.chunks_exact(8)) {
+            *d = u64::from_le_bytes(raw.try_into().unwrap());
+        }
+
+        let start = *offsets.get(i).ok_or_else(|| truncated("this element's offset"))? as usize;
+        let end = *offsets
+            .get(i + 1)
+            .ok_or_else(|| truncated("this element's offset"))? as usize;
+        let elem_buf = values
+            .get(start..end)
+            .ok_or_else(|| truncated("this element's value bytes"))?;
+
+        let value = T::decode(header.data_version, &dims, elem_buf).map_err(Error::Io)?;
+        items.push((dims, value));
+    }
+
+    Ok((pos, header.data_version, items))
+}
+
+/// Drives a single chunk's fetch-and-decode to completion.
+///
+/// Several of these are kept in flight at once by [`Iter`] so the next
+/// chunk's IO overlaps with decoding of the current one. A chunk that
+/// finishes out of order is held as `Resolved` rather than handed to
+/// `Iter` right away: only the head of `Iter::pending` - the chunk
+/// `shape_iter` produced earliest - is ever turned into `Lazy`s, so
+/// prefetching never reorders the scan's output.
 enum ChunkIter<'a, T: Data, const DIMS: usize, Io: IoHandle> {
-    Pre(Pin<Box<dyn std::future::Future<Output = futures_lite::io::Result<Io::Read>> + Send + 'a>>),
-    InProcess(Io::Read, &'a World<T, DIMS, Io>),
+    Pending(
+        Pin<
+            Box<
+                dyn Future<Output = Result<([usize; DIMS], u32, Vec<([u64; DIMS], T)>), Error>>
+                    + Send
+                    + 'a,
+            >,
+        >,
+    ),
+    Resolved(Result<([usize; DIMS], u32, Vec<([u64; DIMS], T)>), Error>),
 }
 
-impl<T: Data, const DIMS: usize, Io: IoHandle> Stream for ChunkIter<'_, T, DIMS, Io> {
-    type Item = ();
+impl<'a, T: Data, const DIMS: usize, Io: IoHandle> ChunkIter<'a, T, DIMS, Io> {
+    fn new(world: &'a World<T, DIMS, Io>, pos: [usize; DIMS]) -> Self {
+        ChunkIter::Pending(Box::pin(fetch_chunk(world, pos)))
+    }
 
-    fn poll_next(
-        self: Pin<&mut Self>,
-        cx: &mut std::task::Context<'_>,
-    ) -> std::task::Poll<Option<Self::Item>> {
-        let this = self.get_mut();
-        todo!()
+    /// Drives the underlying fetch forward if still in flight, stashing
+    /// its result as `Resolved` the moment it completes.
+    ///
+    /// Returns `Poll::Ready(())` once this chunk has a result available
+    /// (whether just now or on an earlier call), so it's safe to call
+    /// on an already-`Resolved` entry.
+    fn poll(&mut self, cx: &mut std::task::Context<'_>) -> Poll<()> {
+        match self {
+            ChunkIter::Pending(fut) => {
+                let res = ready!(fut.as_mut().poll(cx));
+                *self = ChunkIter::Resolved(res);
+                Poll::Ready(())
+            }
+            ChunkIter::Resolved(_) => Poll::Ready(()),
+        }
     }
 }
 
+/// A stream over all entries selected from a [`World`], prefetching
+/// several chunks concurrently so IO latency for upcoming chunks
+/// overlaps with decoding of the current one.
+///
+/// Concurrency only overlaps IO/decode work; it never reorders output.
+/// `pending` holds chunks in the order `shape_iter` produced them, and
+/// only its head is ever turned into `Lazy`s once resolved, so a chunk
+/// that happens to finish before one requested earlier just waits -
+/// `Iter` yields items in exactly the order a `prefetch(1)` scan would.
 pub struct Iter<'a, T: Data, const DIMS: usize, Io: IoHandle> {
     world: &'a World<T, DIMS, Io>,
     shape_iter: super::select::RawShapeIter<'a, DIMS>,
+    /// Generation snapshot shared by every `Lazy` this iterator
+    /// produces, so a concurrent structural change to the world is
+    /// still caught via `Error::IterUpdated`.
+    gen: LazyCheckState,
+    /// Maximum number of chunks fetched concurrently.
+    prefetch: usize,
+    pending: VecDeque<ChunkIter<'a, T, DIMS, Io>>,
+    ready: VecDeque<Lazy<'a, T, DIMS, Io>>,
+    /// Set by [`Iter::abortable`]; checked at each chunk boundary
+    /// before issuing the next `read_chunk`.
+    abort: Option<Arc<std::sync::atomic::AtomicBool>>,
+    done: bool,
 }
 
-impl<T: Data, const DIMS: usize, Io: IoHandle> Stream for Iter<'_, T, DIMS, Io> {
-    type Item = ();
+/// A handle that cooperatively cancels an [`Iter`] scan from another
+/// task.
+///
+/// Created by [`Iter::abortable`]. Calling [`abort`](Self::abort) makes
+/// the scan's next `poll_next` resolve to a terminal
+/// `Err(Error::Aborted)` instead of issuing another `read_chunk`,
+/// dropping any chunk reader still in flight.
+#[derive(Clone)]
+pub struct AbortHandle {
+    flag: Arc<std::sync::atomic::AtomicBool>,
+}
+
+impl AbortHandle {
+    /// Requests cancellation of the associated scan.
+    pub fn abort(&self) {
+        self.flag.store(true, atomic::Ordering::Release);
+    }
+
+    /// Returns whether [`abort`](Self::abort) has been called.
+    pub fn is_aborted(&self) -> bool {
+        self.flag.load(atomic::Ordering::Acquire)
+    }
+}
+
+impl<'a, T: Data, const DIMS: usize, Io: IoHandle> Iter<'a, T, DIMS, Io> {
+    pub(crate) fn new(
+        world: &'a World<T, DIMS, Io>,
+        shape_iter: super::select::RawShapeIter<'a, DIMS>,
+        gen: LazyCheckState,
+    ) -> Self {
+        Self {
+            world,
+            shape_iter,
+            gen,
+            prefetch: 1,
+            pending: VecDeque::new(),
+            ready: VecDeque::new(),
+            abort: None,
+            done: false,
+        }
+    }
+
+    /// Sets how many chunks this iterator fetches concurrently ahead of
+    /// decoding, overlapping IO latency for upcoming chunks with
+    /// decoding of the current one. Defaults to `1` (sequential).
+    ///
+    /// Output order is unaffected by this: items are always yielded in
+    /// `shape_iter` order, regardless of which prefetched chunk's IO
+    /// happens to finish first.
+    ///
+    /// This is the knob behind `World::iter_buffered`/`Select::prefetch`.
+    pub fn prefetch(mut self, n: usize) -> Self {
+        self.prefetch = n.max(1);
+        self
+    }
+
+    /// Wraps this scan with an [`AbortHandle`] so it can be cancelled
+    /// cooperatively from another task, e.g. to enforce a query timeout
+    /// or react to a client disconnect.
+    ///
+    /// `World::select(...).abortable()` is expected to thread through to
+    /// this once a `Select` resolves into an `Iter`.
+    pub fn abortable(mut self) -> (Self, AbortHandle) {
+        let flag = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        self.abort = Some(flag.clone());
+        (self, AbortHandle { flag })
+    }
+
+    fn fill_pending(&mut self) {
+        while self.pending.len() < self.prefetch {
+            match self.shape_iter.next() {
+                Some(pos) => self.pending.push_back(ChunkIter::new(self.world, pos)),
+                None => break,
+            }
+        }
+    }
+}
+
+impl<'a, T: Data, const DIMS: usize, Io: IoHandle> Stream for Iter<'a, T, DIMS, Io> {
+    type Item = Result<Lazy<'a, T, DIMS, Io>, Error>;
 
     fn poll_next(
         self: std::pin::Pin<&mut Self>,
         cx: &mut std::task::Context<'_>,
     ) -> std::task::Poll<Option<Self::Item>> {
         let this = self.get_mut();
-        todo!()
+
+        loop {
+            if this.done {
+                return Poll::Ready(None);
+            }
+
+            if let Some(flag) = &this.abort {
+                if flag.load(atomic::Ordering::Acquire) {
+                    // Drop every in-flight reader instead of polling it
+                    // to completion.
+                    this.pending.clear();
+                    this.ready.clear();
+                    this.done = true;
+                    return Poll::Ready(Some(Err(Error::Aborted)));
+                }
+            }
+
+            if let Some(item) = this.ready.pop_front() {
+                return Poll::Ready(Some(Ok(item)));
+            }
+
+            this.fill_pending();
+
+            if this.pending.is_empty() {
+                this.done = true;
+                return Poll::Ready(None);
+            }
+
+            // Drive every in-flight chunk forward, so prefetched IO
+            // overlaps, but only ever drain from the front: a chunk
+            // that resolves out of turn just sits as `Resolved` until
+            // the ones requested before it have been released too,
+            // keeping `ready` (and thus this stream's output) in
+            // `shape_iter` order.
+            let mut any_pending = false;
+            for item in this.pending.iter_mut() {
+                if item.poll(cx).is_pending() {
+                    any_pending = true;
+                }
+            }
+
+            while let Some(ChunkIter::Resolved(_)) = this.pending.front() {
+                let Some(ChunkIter::Resolved(result)) = this.pending.pop_front() else {
+                    unreachable!()
+                };
+                match result {
+                    Ok((_pos, version, items)) => {
+                        for (dims, value) in items {
+                            let cell = OnceLock::new();
+                            let _ = cell.set(Value::Direct(value));
+                            this.ready.push_back(Lazy {
+                                world: this.world,
+                                dims,
+                                read_type: ReadType::Io { version, len: 0 },
+                                value: cell,
+                                read: std::sync::Mutex::new(None),
+                                state: this.gen.clone(),
+                            });
+                        }
+                    }
+                    Err(err) => return Poll::Ready(Some(Err(err))),
+                }
+            }
+
+            if !this.ready.is_empty() {
+                continue;
+            }
+
+            if any_pending {
+                return Poll::Pending;
+            }
+        }
     }
 }